@@ -0,0 +1,646 @@
+//! The client's API as a trait, so code using it can be tested without a
+//! server.
+//!
+//! ```
+//! use std::sync::Arc;
+//! use radio_browser_api::{MockRadioBrowser, RadioBrowserApi, RadioStation};
+//!
+//! async fn first_jazz_station(api: &dyn RadioBrowserApi) -> Option<String> {
+//!     let stations = api.search_by_tag("jazz", 1).await.ok()?;
+//!     stations.into_iter().next().map(|station| station.name)
+//! }
+//!
+//! # tokio_test::block_on(async {
+//! let station = RadioStation {
+//!     name: "Jazz FM".to_string(),
+//!     tags: Some("jazz,smooth".to_string()),
+//!     ..Default::default()
+//! };
+//! let api: Arc<dyn RadioBrowserApi + Send + Sync> = Arc::new(MockRadioBrowser::new(vec![station]));
+//!
+//! assert_eq!(first_jazz_station(&*api).await.as_deref(), Some("Jazz FM"));
+//! # });
+//! ```
+
+use std::sync::Mutex;
+use std::time::Duration;
+use async_trait::async_trait;
+
+use crate::{
+    AddStationResult, CheckStep, ClickResult, CodecInfo, CountryInfo, LanguageInfo, NewStation, ProbeResult,
+    RadioBrowserClient, RadioBrowserError, RadioStation, ServerConfig, ServerEntry, ServerStats, StateInfo,
+    StationCheck, StationClick, StationUuid, TagInfo, TagSearchResults, VoteResult,
+};
+
+/// The public methods of [`RadioBrowserClient`]. Object safe, so services
+/// can hold an `Arc<dyn RadioBrowserApi + Send + Sync>` and get a
+/// [`MockRadioBrowser`] in tests. Uuids are taken as `&str` here; see the
+/// client methods for details of each call. On wasm32 the futures are not
+/// `Send`, like everything else in the browser.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait RadioBrowserApi {
+    async fn search_by_tag(&self, tag: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError>;
+
+    async fn search_all_by_tag(&self, tag: &str, page_size: usize) -> Result<Vec<RadioStation>, RadioBrowserError>;
+
+    /// Searches the tags one after another; the client runs them concurrently.
+    async fn search_by_tags(&self, tags: &[&str], limit_per_tag: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        let mut stations = Vec::new();
+        for tag in tags {
+            stations.extend(self.search_by_tag(tag, limit_per_tag).await?);
+        }
+        Ok(crate::dedup_stations(stations))
+    }
+
+    async fn search_by_tags_partial(&self, tags: &[&str], limit_per_tag: usize) -> TagSearchResults {
+        let mut results = TagSearchResults::default();
+        for tag in tags {
+            match self.search_by_tag(tag, limit_per_tag).await {
+                Ok(stations) => results.stations.extend(stations),
+                Err(e) => results.errors.push((tag.to_string(), e)),
+            }
+        }
+        results.stations = crate::dedup_stations(results.stations);
+        results
+    }
+
+    async fn similar_stations(&self, station: &RadioStation, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        let (tags, limit_per_tag) = crate::similar_search(station, limit);
+        if tags.is_empty() || limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        let tags: Vec<&str> = tags.iter().map(String::as_str).collect();
+        let mut results = self.search_by_tags_partial(&tags, limit_per_tag).await;
+        if results.errors.len() == tags.len() {
+            return Err(results.errors.remove(0).1);
+        }
+        Ok(crate::rank_similar(station, results.stations, limit))
+    }
+
+    async fn search_by_name(&self, name: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError>;
+
+    async fn search_by_country(&self, country: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError>;
+
+    async fn search_by_countrycode(&self, code: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError>;
+
+    async fn search_by_language(&self, language: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError>;
+
+    async fn search_by_state(&self, state: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError>;
+
+    async fn search_by_codec(&self, codec: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError>;
+
+    async fn search_nearby(&self, lat: f64, long: f64, radius_km: f64, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError>;
+
+    async fn random_stations(&self, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError>;
+
+    async fn station_by_uuid(&self, uuid: &str) -> Result<Option<RadioStation>, RadioBrowserError>;
+
+    async fn stations_by_uuids(&self, uuids: &[&str]) -> Result<Vec<RadioStation>, RadioBrowserError>;
+
+    async fn top_clicks(&self, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError>;
+
+    async fn top_votes(&self, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError>;
+
+    async fn recently_clicked(&self, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError>;
+
+    async fn recently_changed(&self, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError>;
+
+    async fn broken_stations(&self, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError>;
+
+    async fn vote(&self, station_uuid: &str) -> Result<VoteResult, RadioBrowserError>;
+
+    async fn click(&self, station_uuid: &str) -> Result<ClickResult, RadioBrowserError>;
+
+    async fn add_station(&self, new: NewStation) -> Result<AddStationResult, RadioBrowserError>;
+
+    async fn server_stats(&self) -> Result<ServerStats, RadioBrowserError>;
+
+    async fn server_config(&self) -> Result<ServerConfig, RadioBrowserError>;
+
+    async fn list_servers(&self) -> Result<Vec<ServerEntry>, RadioBrowserError>;
+
+    async fn resolve_stream_url(&self, station: &RadioStation) -> Result<reqwest::Url, RadioBrowserError>;
+
+    async fn probe(&self, station: &RadioStation, timeout: Duration) -> Result<ProbeResult, RadioBrowserError>;
+
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn icy_metadata(&self, station: &RadioStation) -> Result<Option<crate::IcyMetadata>, RadioBrowserError>;
+
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn fetch_favicon(&self, station: &RadioStation, max_bytes: usize) -> Result<Option<crate::FaviconData>, RadioBrowserError>;
+
+    async fn station_checks(&self, uuid: &str, last_check_uuid: Option<&str>) -> Result<Vec<StationCheck>, RadioBrowserError>;
+
+    async fn station_clicks(&self, uuid: Option<&str>, last_click_uuid: Option<&str>) -> Result<Vec<StationClick>, RadioBrowserError>;
+
+    async fn station_clicks_since(&self, uuid: Option<&str>, seconds: u64) -> Result<Vec<StationClick>, RadioBrowserError>;
+
+    async fn check_steps(&self, uuids: &[&str]) -> Result<Vec<CheckStep>, RadioBrowserError>;
+
+    async fn list_tags(&self, filter: Option<&str>, limit: Option<usize>) -> Result<Vec<TagInfo>, RadioBrowserError>;
+
+    async fn list_countries(&self, filter: Option<&str>) -> Result<Vec<CountryInfo>, RadioBrowserError>;
+
+    async fn list_languages(&self, order_by_count: bool) -> Result<Vec<LanguageInfo>, RadioBrowserError>;
+
+    async fn list_codecs(&self) -> Result<Vec<CodecInfo>, RadioBrowserError>;
+
+    async fn list_states(&self, country: Option<&str>, filter: Option<&str>) -> Result<Vec<StateInfo>, RadioBrowserError>;
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl RadioBrowserApi for RadioBrowserClient {
+    async fn search_by_tag(&self, tag: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        RadioBrowserClient::search_by_tag(self, tag, limit).await
+    }
+
+    async fn search_all_by_tag(&self, tag: &str, page_size: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        RadioBrowserClient::search_all_by_tag(self, tag, page_size).await
+    }
+
+    async fn search_by_tags(&self, tags: &[&str], limit_per_tag: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        RadioBrowserClient::search_by_tags(self, tags, limit_per_tag).await
+    }
+
+    async fn search_by_tags_partial(&self, tags: &[&str], limit_per_tag: usize) -> TagSearchResults {
+        RadioBrowserClient::search_by_tags_partial(self, tags, limit_per_tag).await
+    }
+
+    async fn similar_stations(&self, station: &RadioStation, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        RadioBrowserClient::similar_stations(self, station, limit).await
+    }
+
+    async fn search_by_name(&self, name: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        RadioBrowserClient::search_by_name(self, name, limit).await
+    }
+
+    async fn search_by_country(&self, country: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        RadioBrowserClient::search_by_country(self, country, limit).await
+    }
+
+    async fn search_by_countrycode(&self, code: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        RadioBrowserClient::search_by_countrycode(self, code, limit).await
+    }
+
+    async fn search_by_language(&self, language: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        RadioBrowserClient::search_by_language(self, language, limit).await
+    }
+
+    async fn search_by_state(&self, state: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        RadioBrowserClient::search_by_state(self, state, limit).await
+    }
+
+    async fn search_by_codec(&self, codec: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        RadioBrowserClient::search_by_codec(self, codec, limit).await
+    }
+
+    async fn search_nearby(&self, lat: f64, long: f64, radius_km: f64, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        RadioBrowserClient::search_nearby(self, lat, long, radius_km, limit).await
+    }
+
+    async fn random_stations(&self, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        RadioBrowserClient::random_stations(self, limit).await
+    }
+
+    async fn station_by_uuid(&self, uuid: &str) -> Result<Option<RadioStation>, RadioBrowserError> {
+        RadioBrowserClient::station_by_uuid(self, uuid).await
+    }
+
+    async fn stations_by_uuids(&self, uuids: &[&str]) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        RadioBrowserClient::stations_by_uuids(self, uuids).await
+    }
+
+    async fn top_clicks(&self, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        RadioBrowserClient::top_clicks(self, limit).await
+    }
+
+    async fn top_votes(&self, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        RadioBrowserClient::top_votes(self, limit).await
+    }
+
+    async fn recently_clicked(&self, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        RadioBrowserClient::recently_clicked(self, limit).await
+    }
+
+    async fn recently_changed(&self, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        RadioBrowserClient::recently_changed(self, limit).await
+    }
+
+    async fn broken_stations(&self, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        RadioBrowserClient::broken_stations(self, limit).await
+    }
+
+    async fn vote(&self, station_uuid: &str) -> Result<VoteResult, RadioBrowserError> {
+        RadioBrowserClient::vote(self, station_uuid).await
+    }
+
+    async fn click(&self, station_uuid: &str) -> Result<ClickResult, RadioBrowserError> {
+        RadioBrowserClient::click(self, station_uuid).await
+    }
+
+    async fn add_station(&self, new: NewStation) -> Result<AddStationResult, RadioBrowserError> {
+        RadioBrowserClient::add_station(self, new).await
+    }
+
+    async fn server_stats(&self) -> Result<ServerStats, RadioBrowserError> {
+        RadioBrowserClient::server_stats(self).await
+    }
+
+    async fn server_config(&self) -> Result<ServerConfig, RadioBrowserError> {
+        RadioBrowserClient::server_config(self).await
+    }
+
+    async fn list_servers(&self) -> Result<Vec<ServerEntry>, RadioBrowserError> {
+        RadioBrowserClient::list_servers(self).await
+    }
+
+    async fn resolve_stream_url(&self, station: &RadioStation) -> Result<reqwest::Url, RadioBrowserError> {
+        RadioBrowserClient::resolve_stream_url(self, station).await
+    }
+
+    async fn probe(&self, station: &RadioStation, timeout: Duration) -> Result<ProbeResult, RadioBrowserError> {
+        RadioBrowserClient::probe(self, station, timeout).await
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn icy_metadata(&self, station: &RadioStation) -> Result<Option<crate::IcyMetadata>, RadioBrowserError> {
+        RadioBrowserClient::icy_metadata(self, station).await
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn fetch_favicon(&self, station: &RadioStation, max_bytes: usize) -> Result<Option<crate::FaviconData>, RadioBrowserError> {
+        RadioBrowserClient::fetch_favicon(self, station, max_bytes).await
+    }
+
+    async fn station_checks(&self, uuid: &str, last_check_uuid: Option<&str>) -> Result<Vec<StationCheck>, RadioBrowserError> {
+        RadioBrowserClient::station_checks(self, uuid, last_check_uuid).await
+    }
+
+    async fn station_clicks(&self, uuid: Option<&str>, last_click_uuid: Option<&str>) -> Result<Vec<StationClick>, RadioBrowserError> {
+        RadioBrowserClient::station_clicks(self, uuid, last_click_uuid).await
+    }
+
+    async fn station_clicks_since(&self, uuid: Option<&str>, seconds: u64) -> Result<Vec<StationClick>, RadioBrowserError> {
+        RadioBrowserClient::station_clicks_since(self, uuid, seconds).await
+    }
+
+    async fn check_steps(&self, uuids: &[&str]) -> Result<Vec<CheckStep>, RadioBrowserError> {
+        RadioBrowserClient::check_steps(self, uuids).await
+    }
+
+    async fn list_tags(&self, filter: Option<&str>, limit: Option<usize>) -> Result<Vec<TagInfo>, RadioBrowserError> {
+        RadioBrowserClient::list_tags(self, filter, limit).await
+    }
+
+    async fn list_countries(&self, filter: Option<&str>) -> Result<Vec<CountryInfo>, RadioBrowserError> {
+        RadioBrowserClient::list_countries(self, filter).await
+    }
+
+    async fn list_languages(&self, order_by_count: bool) -> Result<Vec<LanguageInfo>, RadioBrowserError> {
+        RadioBrowserClient::list_languages(self, order_by_count).await
+    }
+
+    async fn list_codecs(&self) -> Result<Vec<CodecInfo>, RadioBrowserError> {
+        RadioBrowserClient::list_codecs(self).await
+    }
+
+    async fn list_states(&self, country: Option<&str>, filter: Option<&str>) -> Result<Vec<StateInfo>, RadioBrowserError> {
+        RadioBrowserClient::list_states(self, country, filter).await
+    }
+}
+
+/// An in-memory [`RadioBrowserApi`] serving a fixed list of stations.
+///
+/// Searches filter the list the way the server would (tag, exact country,
+/// substring of the name, ...) and return at most `limit` stations in list
+/// order; top lists sort it by clicks or votes. Votes and clicks succeed for
+/// uuids in the list. Listings, checks and clicks are empty. Every call is
+/// recorded by name, see [`calls`](Self::calls).
+#[derive(Debug, Default)]
+pub struct MockRadioBrowser {
+    stations: Vec<RadioStation>,
+    calls: Mutex<Vec<&'static str>>,
+}
+
+impl MockRadioBrowser {
+    pub fn new(stations: Vec<RadioStation>) -> Self {
+        Self {
+            stations,
+            calls: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Names of the methods called so far, e.g. `["search_by_tag", "vote"]`.
+    pub fn calls(&self) -> Vec<&'static str> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    fn record(&self, call: &'static str) {
+        self.calls.lock().unwrap().push(call);
+    }
+
+    // Лимит проверяется как у клиента, чтобы limit == 0 не проходил только в тестах
+    fn find(
+        &self,
+        call: &'static str,
+        limit: usize,
+        filter: impl Fn(&RadioStation) -> bool,
+    ) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.record(call);
+        self.select(limit, filter)
+    }
+
+    fn select(&self, limit: usize, filter: impl Fn(&RadioStation) -> bool) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        crate::validate_limit(limit)?;
+        Ok(self.stations.iter().filter(|station| filter(station)).take(limit).cloned().collect())
+    }
+
+    fn sorted(
+        &self,
+        call: &'static str,
+        limit: usize,
+        key: impl Fn(&RadioStation) -> i64,
+    ) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.record(call);
+        crate::validate_limit(limit)?;
+        let mut stations = self.stations.clone();
+        stations.sort_by_key(|station| std::cmp::Reverse(key(station)));
+        stations.truncate(limit);
+        Ok(stations)
+    }
+
+    fn by_uuid(&self, uuid: &str) -> Result<Option<&RadioStation>, RadioBrowserError> {
+        let uuid: StationUuid = uuid.parse()?;
+        Ok(self.stations.iter().find(|station| station.stationuuid.as_ref() == Some(&uuid)))
+    }
+}
+
+// Формула гаверсинусов на сфере радиусом 6371 км
+fn distance_m((lat1, long1): (f64, f64), (lat2, long2): (f64, f64)) -> f64 {
+    let (dlat, dlong) = ((lat2 - lat1).to_radians(), (long2 - long1).to_radians());
+    let a = (dlat / 2.0).sin().powi(2) + lat1.to_radians().cos() * lat2.to_radians().cos() * (dlong / 2.0).sin().powi(2);
+    2.0 * 6_371_000.0 * a.sqrt().asin()
+}
+
+fn field_eq(field: &Option<String>, value: &str) -> bool {
+    field.as_deref().is_some_and(|field| field.eq_ignore_ascii_case(value))
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl RadioBrowserApi for MockRadioBrowser {
+    async fn search_by_tag(&self, tag: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.find("search_by_tag", limit, |station| station.has_tag(tag))
+    }
+
+    async fn search_all_by_tag(&self, tag: &str, page_size: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.record("search_all_by_tag");
+        if page_size == 0 {
+            return Err(RadioBrowserError::InvalidParameter("page size must be greater than zero".to_string()));
+        }
+        Ok(self.stations.iter().filter(|station| station.has_tag(tag)).cloned().collect())
+    }
+
+    async fn search_by_name(&self, name: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        let name = name.to_lowercase();
+        self.find("search_by_name", limit, |station| station.name.to_lowercase().contains(&name))
+    }
+
+    async fn search_by_country(&self, country: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.find("search_by_country", limit, |station| field_eq(&station.country, country))
+    }
+
+    async fn search_by_countrycode(&self, code: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.record("search_by_countrycode");
+        crate::validate_countrycode(code)?;
+        self.select(limit, |station| field_eq(&station.countrycode, code))
+    }
+
+    async fn search_by_language(&self, language: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.record("search_by_language");
+        crate::validate_filter("language", language)?;
+        self.select(limit, |station| field_eq(&station.language, language))
+    }
+
+    async fn search_by_state(&self, state: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.record("search_by_state");
+        crate::validate_filter("state", state)?;
+        self.select(limit, |station| field_eq(&station.state, state))
+    }
+
+    async fn search_by_codec(&self, codec: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.record("search_by_codec");
+        crate::validate_filter("codec", codec)?;
+        self.select(limit, |station| field_eq(&station.codec, codec))
+    }
+
+    // Как и сервер, заполняет geo_distance; радиус округляется до метров, как в запросе клиента
+    async fn search_nearby(&self, lat: f64, long: f64, radius_km: f64, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.record("search_nearby");
+        crate::validate_near(lat, long, (radius_km * 1000.0).round())?;
+        crate::validate_limit(limit)?;
+        let mut stations: Vec<RadioStation> = self
+            .stations
+            .iter()
+            .filter_map(|station| {
+                let distance = distance_m((lat, long), (station.geo_lat?, station.geo_long?));
+                (distance <= radius_km * 1000.0).then(|| RadioStation {
+                    geo_distance: Some(distance),
+                    ..station.clone()
+                })
+            })
+            .collect();
+        stations.truncate(limit);
+        Ok(stations)
+    }
+
+    // Случайность в тестах только мешает: первые limit станций
+    async fn random_stations(&self, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.find("random_stations", limit, |_| true)
+    }
+
+    async fn station_by_uuid(&self, uuid: &str) -> Result<Option<RadioStation>, RadioBrowserError> {
+        self.record("station_by_uuid");
+        Ok(self.by_uuid(uuid)?.cloned())
+    }
+
+    async fn stations_by_uuids(&self, uuids: &[&str]) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.record("stations_by_uuids");
+        let mut stations = Vec::new();
+        for uuid in uuids {
+            stations.extend(self.by_uuid(uuid)?.cloned());
+        }
+        Ok(stations)
+    }
+
+    async fn top_clicks(&self, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.sorted("top_clicks", limit, |station| station.clickcount.unwrap_or_default() as i64)
+    }
+
+    async fn top_votes(&self, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.sorted("top_votes", limit, |station| station.votes.unwrap_or_default())
+    }
+
+    async fn recently_clicked(&self, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.find("recently_clicked", limit, |_| true)
+    }
+
+    async fn recently_changed(&self, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.find("recently_changed", limit, |_| true)
+    }
+
+    async fn broken_stations(&self, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.find("broken_stations", limit, |station| station.lastcheckok == Some(false))
+    }
+
+    async fn vote(&self, station_uuid: &str) -> Result<VoteResult, RadioBrowserError> {
+        self.record("vote");
+        match self.by_uuid(station_uuid)? {
+            Some(_) => Ok(VoteResult {
+                ok: true,
+                message: "voted for station successfully".to_string(),
+            }),
+            None => Err(RadioBrowserError::VoteRejected(
+                "VoteError 'could not find station with matching id'".to_string(),
+            )),
+        }
+    }
+
+    async fn click(&self, station_uuid: &str) -> Result<ClickResult, RadioBrowserError> {
+        self.record("click");
+        match self.by_uuid(station_uuid)? {
+            Some(station) => Ok(ClickResult {
+                ok: true,
+                message: "retrieved station url".to_string(),
+                name: station.name.clone(),
+                url: station.stream_url().to_string(),
+            }),
+            None => Err(RadioBrowserError::ClickRejected("did not find station with matching id".to_string())),
+        }
+    }
+
+    async fn add_station(&self, _new: NewStation) -> Result<AddStationResult, RadioBrowserError> {
+        self.record("add_station");
+        Ok(AddStationResult {
+            ok: true,
+            message: "added station successfully".to_string(),
+            uuid: None,
+        })
+    }
+
+    async fn server_stats(&self) -> Result<ServerStats, RadioBrowserError> {
+        self.record("server_stats");
+        Ok(ServerStats {
+            supported_version: 1,
+            software_version: None,
+            status: "OK".to_string(),
+            stations: self.stations.len() as u64,
+            stations_broken: self.stations.iter().filter(|station| station.lastcheckok == Some(false)).count() as u64,
+            tags: 0,
+            clicks_last_hour: 0,
+            clicks_last_day: 0,
+            languages: None,
+            countries: None,
+        })
+    }
+
+    async fn server_config(&self) -> Result<ServerConfig, RadioBrowserError> {
+        self.record("server_config");
+        Ok(ServerConfig::default())
+    }
+
+    async fn list_servers(&self) -> Result<Vec<ServerEntry>, RadioBrowserError> {
+        self.record("list_servers");
+        Ok(Vec::new())
+    }
+
+    // Без сети: адрес берётся из самой станции
+    async fn resolve_stream_url(&self, station: &RadioStation) -> Result<reqwest::Url, RadioBrowserError> {
+        self.record("resolve_stream_url");
+        let url = station.stream_url();
+        reqwest::Url::parse(url).map_err(|e| RadioBrowserError::InvalidParameter(format!("invalid stream url {:?}: {}", url, e)))
+    }
+
+    // Доступной считается станция, чья последняя проверка не провалилась
+    async fn probe(&self, station: &RadioStation, _timeout: Duration) -> Result<ProbeResult, RadioBrowserError> {
+        self.record("probe");
+        let url = station.stream_url();
+        let url = reqwest::Url::parse(url)
+            .map_err(|e| RadioBrowserError::InvalidParameter(format!("invalid stream url {:?}: {}", url, e)))?;
+        let reachable = station.lastcheckok != Some(false);
+        Ok(ProbeResult {
+            reachable,
+            url,
+            status: reachable.then_some(200),
+            content_type: None,
+            icy: false,
+        })
+    }
+
+    // Заголовки берутся из полей станции, заголовка трека нет
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn icy_metadata(&self, station: &RadioStation) -> Result<Option<crate::IcyMetadata>, RadioBrowserError> {
+        self.record("icy_metadata");
+        Ok(Some(crate::IcyMetadata {
+            stream_title: None,
+            name: Some(station.name.clone()),
+            bitrate: station.bitrate.filter(|&bitrate| bitrate > 0),
+            genre: station.tags.clone().filter(|tags| !tags.is_empty()),
+        }))
+    }
+
+    // Картинок у мока нет
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn fetch_favicon(&self, _station: &RadioStation, _max_bytes: usize) -> Result<Option<crate::FaviconData>, RadioBrowserError> {
+        self.record("fetch_favicon");
+        Ok(None)
+    }
+
+    async fn station_checks(&self, _uuid: &str, _last_check_uuid: Option<&str>) -> Result<Vec<StationCheck>, RadioBrowserError> {
+        self.record("station_checks");
+        Ok(Vec::new())
+    }
+
+    async fn station_clicks(&self, _uuid: Option<&str>, _last_click_uuid: Option<&str>) -> Result<Vec<StationClick>, RadioBrowserError> {
+        self.record("station_clicks");
+        Ok(Vec::new())
+    }
+
+    async fn station_clicks_since(&self, _uuid: Option<&str>, _seconds: u64) -> Result<Vec<StationClick>, RadioBrowserError> {
+        self.record("station_clicks_since");
+        Ok(Vec::new())
+    }
+
+    async fn check_steps(&self, _uuids: &[&str]) -> Result<Vec<CheckStep>, RadioBrowserError> {
+        self.record("check_steps");
+        Ok(Vec::new())
+    }
+
+    async fn list_tags(&self, _filter: Option<&str>, _limit: Option<usize>) -> Result<Vec<TagInfo>, RadioBrowserError> {
+        self.record("list_tags");
+        Ok(Vec::new())
+    }
+
+    async fn list_countries(&self, _filter: Option<&str>) -> Result<Vec<CountryInfo>, RadioBrowserError> {
+        self.record("list_countries");
+        Ok(Vec::new())
+    }
+
+    async fn list_languages(&self, _order_by_count: bool) -> Result<Vec<LanguageInfo>, RadioBrowserError> {
+        self.record("list_languages");
+        Ok(Vec::new())
+    }
+
+    async fn list_codecs(&self) -> Result<Vec<CodecInfo>, RadioBrowserError> {
+        self.record("list_codecs");
+        Ok(Vec::new())
+    }
+
+    async fn list_states(&self, _country: Option<&str>, _filter: Option<&str>) -> Result<Vec<StateInfo>, RadioBrowserError> {
+        self.record("list_states");
+        Ok(Vec::new())
+    }
+}
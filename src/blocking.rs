@@ -0,0 +1,312 @@
+//! A synchronous client for programs without an async runtime.
+//!
+//! Every call runs the async [`crate::RadioBrowserClient`] on a small
+//! runtime with one worker thread owned by the client, so queries, caching
+//! and errors behave exactly the same. Background cache refreshes keep
+//! running on that thread between calls. Calling it from inside an async runtime
+//! panics; use the async client there.
+
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+use crate::{
+    AddStationResult, CacheStats, CheckStep, ClickResult, CodecInfo, CountryInfo, FaviconData, Favorites, FavoritesRefresh, IcyMetadata,
+    LanguageInfo, MirrorResolver, NewStation, Order, ProbeResult, RadioBrowserError, RadioStation, ServerConfig,
+    ServerEntry, ServerStats, StateInfo, StationCheck, StationClick, TagInfo, TagSearchResults, VoteResult,
+};
+
+#[derive(Clone, Debug)]
+pub struct RadioBrowserClient {
+    inner: crate::RadioBrowserClient,
+    runtime: Arc<Runtime>,
+}
+
+impl Default for RadioBrowserClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Настраивается async-клиент, а блокирующий только оборачивает его
+impl From<crate::RadioBrowserClient> for RadioBrowserClient {
+    fn from(inner: crate::RadioBrowserClient) -> Self {
+        // Фоновые обновления stale-while-revalidate идут на рабочем потоке, а не только внутри block_on
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()
+            .expect("failed to build tokio runtime");
+
+        Self {
+            inner,
+            runtime: Arc::new(runtime),
+        }
+    }
+}
+
+impl RadioBrowserClient {
+    pub fn new() -> Self {
+        crate::RadioBrowserClient::new().into()
+    }
+
+    pub fn discover() -> Result<Self, RadioBrowserError> {
+        Self::discover_with(&crate::DnsResolver)
+    }
+
+    pub fn discover_with(resolver: &dyn MirrorResolver) -> Result<Self, RadioBrowserError> {
+        let client = Self::new();
+        let inner = client.runtime.block_on(crate::RadioBrowserClient::discover_with(resolver))?;
+
+        Ok(Self { inner, ..client })
+    }
+
+    /// The async client this one runs.
+    pub fn as_async(&self) -> &crate::RadioBrowserClient {
+        &self.inner
+    }
+
+    pub fn base_url(&self) -> String {
+        self.inner.base_url()
+    }
+
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.inner.cache_stats()
+    }
+
+    pub fn invalidate_tag(&self, tag: &str) {
+        self.runtime.block_on(self.inner.invalidate_tag(tag))
+    }
+
+    pub fn clear_cache(&self) {
+        self.runtime.block_on(self.inner.clear_cache())
+    }
+
+    pub fn search(&self) -> StationSearch<'_> {
+        StationSearch {
+            inner: self.inner.search(),
+            runtime: &self.runtime,
+        }
+    }
+
+    pub fn search_by_tag(&self, tag: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.search_by_tag(tag, limit))
+    }
+
+    pub fn search_all_by_tag(&self, tag: &str, page_size: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.search_all_by_tag(tag, page_size))
+    }
+
+    pub fn search_by_tags(&self, tags: &[&str], limit_per_tag: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.search_by_tags(tags, limit_per_tag))
+    }
+
+    pub fn search_by_tags_partial(&self, tags: &[&str], limit_per_tag: usize) -> TagSearchResults {
+        self.runtime.block_on(self.inner.search_by_tags_partial(tags, limit_per_tag))
+    }
+
+    pub fn similar_stations(&self, station: &RadioStation, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.similar_stations(station, limit))
+    }
+
+    pub fn search_by_name(&self, name: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.search_by_name(name, limit))
+    }
+
+    pub fn search_by_country(&self, country: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.search_by_country(country, limit))
+    }
+
+    pub fn search_by_countrycode(&self, code: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.search_by_countrycode(code, limit))
+    }
+
+    pub fn search_by_language(&self, language: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.search_by_language(language, limit))
+    }
+
+    pub fn search_by_state(&self, state: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.search_by_state(state, limit))
+    }
+
+    pub fn search_by_codec(&self, codec: &str, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.search_by_codec(codec, limit))
+    }
+
+    pub fn search_nearby(&self, lat: f64, long: f64, radius_km: f64, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.search_nearby(lat, long, radius_km, limit))
+    }
+
+    pub fn random_stations(&self, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.random_stations(limit))
+    }
+
+    pub fn station_by_uuid(&self, uuid: impl AsRef<str>) -> Result<Option<RadioStation>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.station_by_uuid(uuid))
+    }
+
+    pub fn stations_by_uuids<U: AsRef<str>>(&self, uuids: &[U]) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.stations_by_uuids(uuids))
+    }
+
+    pub fn top_clicks(&self, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.top_clicks(limit))
+    }
+
+    pub fn top_votes(&self, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.top_votes(limit))
+    }
+
+    pub fn recently_clicked(&self, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.recently_clicked(limit))
+    }
+
+    pub fn recently_changed(&self, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.recently_changed(limit))
+    }
+
+    pub fn broken_stations(&self, limit: usize) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.broken_stations(limit))
+    }
+
+    pub fn vote(&self, station_uuid: impl AsRef<str>) -> Result<VoteResult, RadioBrowserError> {
+        self.runtime.block_on(self.inner.vote(station_uuid))
+    }
+
+    pub fn click(&self, station_uuid: impl AsRef<str>) -> Result<ClickResult, RadioBrowserError> {
+        self.runtime.block_on(self.inner.click(station_uuid))
+    }
+
+    pub fn add_station(&self, new: NewStation) -> Result<AddStationResult, RadioBrowserError> {
+        self.runtime.block_on(self.inner.add_station(new))
+    }
+
+    pub fn server_stats(&self) -> Result<ServerStats, RadioBrowserError> {
+        self.runtime.block_on(self.inner.server_stats())
+    }
+
+    pub fn server_config(&self) -> Result<ServerConfig, RadioBrowserError> {
+        self.runtime.block_on(self.inner.server_config())
+    }
+
+    pub fn list_servers(&self) -> Result<Vec<ServerEntry>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.list_servers())
+    }
+
+    pub fn resolve_stream_url(&self, station: &RadioStation) -> Result<reqwest::Url, RadioBrowserError> {
+        self.runtime.block_on(self.inner.resolve_stream_url(station))
+    }
+
+    pub fn probe(&self, station: &RadioStation, timeout: Duration) -> Result<ProbeResult, RadioBrowserError> {
+        self.runtime.block_on(self.inner.probe(station, timeout))
+    }
+
+    pub fn icy_metadata(&self, station: &RadioStation) -> Result<Option<IcyMetadata>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.icy_metadata(station))
+    }
+
+    pub fn fetch_favicon(&self, station: &RadioStation, max_bytes: usize) -> Result<Option<FaviconData>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.fetch_favicon(station, max_bytes))
+    }
+
+    /// See [`Favorites::refresh`].
+    pub fn refresh_favorites(&self, favorites: &Favorites) -> Result<FavoritesRefresh, RadioBrowserError> {
+        self.runtime.block_on(favorites.refresh(&self.inner))
+    }
+
+    pub fn station_checks(
+        &self,
+        uuid: impl AsRef<str>,
+        last_check_uuid: Option<&str>,
+    ) -> Result<Vec<StationCheck>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.station_checks(uuid, last_check_uuid))
+    }
+
+    pub fn station_clicks(&self, uuid: Option<&str>, last_click_uuid: Option<&str>) -> Result<Vec<StationClick>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.station_clicks(uuid, last_click_uuid))
+    }
+
+    pub fn station_clicks_since(&self, uuid: Option<&str>, seconds: u64) -> Result<Vec<StationClick>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.station_clicks_since(uuid, seconds))
+    }
+
+    pub fn check_steps<U: AsRef<str>>(&self, uuids: &[U]) -> Result<Vec<CheckStep>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.check_steps(uuids))
+    }
+
+    pub fn list_tags(&self, filter: Option<&str>, limit: Option<usize>) -> Result<Vec<TagInfo>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.list_tags(filter, limit))
+    }
+
+    pub fn list_countries(&self, filter: Option<&str>) -> Result<Vec<CountryInfo>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.list_countries(filter))
+    }
+
+    pub fn list_languages(&self, order_by_count: bool) -> Result<Vec<LanguageInfo>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.list_languages(order_by_count))
+    }
+
+    pub fn list_codecs(&self) -> Result<Vec<CodecInfo>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.list_codecs())
+    }
+
+    pub fn list_states(&self, country: Option<&str>, filter: Option<&str>) -> Result<Vec<StateInfo>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.list_states(country, filter))
+    }
+}
+
+/// Blocking counterpart of [`crate::StationSearch`].
+pub struct StationSearch<'a> {
+    inner: crate::StationSearch<'a>,
+    runtime: &'a Runtime,
+}
+
+macro_rules! forward {
+    ($($name:ident($arg:ident: $ty:ty)),* $(,)?) => {
+        $(
+            pub fn $name(self, $arg: $ty) -> Self {
+                Self {
+                    inner: self.inner.$name($arg),
+                    ..self
+                }
+            }
+        )*
+    };
+}
+
+impl StationSearch<'_> {
+    forward! {
+        name(name: &str),
+        tag(tag: &str),
+        country(country: &str),
+        countrycode(code: &str),
+        state(state: &str),
+        language(language: &str),
+        codec(codec: &str),
+        min_bitrate(bitrate: u32),
+        max_bitrate(bitrate: u32),
+        https_only(https_only: bool),
+        order(order: Order),
+        reverse(reverse: bool),
+        hide_broken(hide_broken: bool),
+        name_exact(exact: bool),
+        tag_exact(exact: bool),
+        country_exact(exact: bool),
+        state_exact(exact: bool),
+        language_exact(exact: bool),
+        force_refresh(force_refresh: bool),
+        offset(offset: usize),
+        limit(limit: usize),
+    }
+
+    pub fn near(self, lat: f64, long: f64, radius_km: f64) -> Self {
+        Self {
+            inner: self.inner.near(lat, long, radius_km),
+            ..self
+        }
+    }
+
+    pub fn execute(self) -> Result<Vec<RadioStation>, RadioBrowserError> {
+        self.runtime.block_on(self.inner.execute())
+    }
+}
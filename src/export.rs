@@ -0,0 +1,153 @@
+//! Playlists of stations for media players. Each entry points at the
+//! station's [`stream_url`](crate::RadioStation::stream_url); stations
+//! without a URL are left out.
+
+use crate::RadioStation;
+
+/// An `#EXTM3U` playlist with an `#EXTINF:-1,<name>` line before each URL.
+pub fn to_m3u(stations: &[RadioStation]) -> String {
+    m3u("#EXTM3U\n", stations)
+}
+
+/// The same playlist for `.m3u8` files, which players always read as UTF-8;
+/// the `#EXTENC` line says so to those that look for it.
+pub fn to_m3u8(stations: &[RadioStation]) -> String {
+    m3u("#EXTM3U\n#EXTENC: UTF-8\n", stations)
+}
+
+/// Writes [`to_m3u8`] to `path`: the text is UTF-8 whatever the extension.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn write_m3u(path: impl AsRef<std::path::Path>, stations: &[RadioStation]) -> std::io::Result<()> {
+    std::fs::write(path, to_m3u8(stations))
+}
+
+/// A PLS (version 2) playlist. Only `http` and `https` streams are listed,
+/// numbered from 1 without gaps.
+pub fn to_pls(stations: &[RadioStation]) -> String {
+    let mut playlist = "[playlist]\n".to_string();
+    let entries: Vec<&RadioStation> = with_url(stations)
+        .filter(|station| {
+            let scheme = station.stream_url().trim().split("://").next().unwrap_or_default().to_ascii_lowercase();
+            scheme == "http" || scheme == "https"
+        })
+        .collect();
+
+    for (index, station) in entries.iter().enumerate() {
+        let number = index + 1;
+        playlist.push_str(&format!(
+            "File{number}={}\nTitle{number}={}\nLength{number}=-1\n",
+            station.stream_url().trim(),
+            single_line(&station.name)
+        ));
+    }
+
+    playlist.push_str(&format!("NumberOfEntries={}\nVersion=2\n", entries.len()));
+    playlist
+}
+
+/// An XSPF 1.0 document with a `<track>` per station: its stream URL as
+/// `<location>`, its name as `<title>`, and tags and country, when known, as
+/// `<annotation>`.
+pub fn to_xspf(stations: &[RadioStation]) -> String {
+    let mut playlist = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+        <playlist version=\"1\" xmlns=\"http://xspf.org/ns/0/\">\n  <trackList>\n"
+        .to_string();
+
+    for station in with_url(stations) {
+        playlist.push_str("    <track>\n");
+        playlist.push_str(&format!("      <location>{}</location>\n", xml_escape(station.stream_url().trim())));
+        playlist.push_str(&format!("      <title>{}</title>\n", xml_escape(&single_line(&station.name))));
+
+        let tags = station.tags_list().join(", ");
+        let country = station.country.as_deref().map(single_line).unwrap_or_default();
+        let annotation: Vec<&str> = [tags.as_str(), country.as_str()].into_iter().filter(|part| !part.is_empty()).collect();
+        if !annotation.is_empty() {
+            playlist.push_str(&format!("      <annotation>{}</annotation>\n", xml_escape(&annotation.join("; "))));
+        }
+        playlist.push_str("    </track>\n");
+    }
+
+    playlist.push_str("  </trackList>\n</playlist>\n");
+    playlist
+}
+
+#[cfg(feature = "export-csv")]
+#[derive(thiserror::Error, Debug)]
+pub enum ExportError {
+    #[error("CSV export failed: {0}")]
+    Csv(#[from] csv::Error),
+}
+
+#[cfg(feature = "export-csv")]
+pub const CSV_COLUMNS: [&str; 11] = [
+    "name", "url", "homepage", "country", "countrycode", "language", "tags", "codec", "bitrate", "votes", "stationuuid",
+];
+
+/// A CSV table with a header row and one row per station, in the order of
+/// [`CSV_COLUMNS`]. Missing values are empty cells; `url` is the stream URL.
+#[cfg(feature = "export-csv")]
+pub fn to_csv(stations: &[RadioStation]) -> Result<String, ExportError> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record(CSV_COLUMNS)?;
+
+    for station in stations {
+        let text = |value: &Option<String>| value.clone().unwrap_or_default();
+        let number = |value: Option<i64>| value.map(|value| value.to_string()).unwrap_or_default();
+        writer.write_record([
+            station.name.clone(),
+            station.stream_url().to_string(),
+            text(&station.homepage),
+            text(&station.country),
+            text(&station.countrycode),
+            text(&station.language),
+            text(&station.tags),
+            text(&station.codec),
+            number(station.bitrate.map(i64::from)),
+            number(station.votes),
+            station.stationuuid.as_ref().map(|uuid| uuid.to_string()).unwrap_or_default(),
+        ])?;
+    }
+
+    let bytes = writer.into_inner().map_err(|e| csv::Error::from(e.into_error()))?;
+    // Все поля - String, так что байты всегда UTF-8
+    Ok(String::from_utf8(bytes).expect("CSV of strings is UTF-8"))
+}
+
+fn m3u(header: &str, stations: &[RadioStation]) -> String {
+    let mut playlist = header.to_string();
+    for station in with_url(stations) {
+        playlist.push_str(&format!("#EXTINF:-1,{}\n{}\n", title(station), station.stream_url().trim()));
+    }
+    playlist
+}
+
+fn with_url(stations: &[RadioStation]) -> impl Iterator<Item = &RadioStation> {
+    stations.iter().filter(|station| !station.stream_url().trim().is_empty())
+}
+
+// Запятую в #EXTINF некоторые плееры считают разделителем полей
+fn title(station: &RadioStation) -> String {
+    single_line(&station.name.replace(',', " "))
+}
+
+// Перевод строки разорвал бы запись плейлиста
+fn single_line(name: &str) -> String {
+    name.split(char::is_control).flat_map(str::split_whitespace).collect::<Vec<_>>().join(" ")
+}
+
+// Управляющие символы в XML 1.0 недопустимы даже экранированными
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
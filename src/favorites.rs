@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use crate::{RadioBrowserClient, RadioBrowserError, RadioStation, StationUuid};
+
+#[cfg(not(target_arch = "wasm32"))]
+const SAVED_FAVORITES_VERSION: u32 = 1;
+
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SavedFavorites {
+    version: u32,
+    stations: Vec<RadioStation>,
+}
+
+/// The user's favorite stations, kept as snapshots in the order they were
+/// added, so they can be shown before any request is made. Every station
+/// has a uuid: that is how [`refresh`](Self::refresh) finds it again.
+/// The list is locked internally and can be shared between tasks through an
+/// `Arc`.
+#[derive(Debug, Default)]
+pub struct Favorites {
+    stations: RwLock<Vec<RadioStation>>,
+}
+
+/// Result of [`Favorites::refresh`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FavoritesRefresh {
+    /// Favorites whose snapshot was replaced with the current station.
+    pub updated: usize,
+    /// Favorites the server no longer knows. They stay in the list with
+    /// their last snapshot until removed.
+    pub missing: Vec<StationUuid>,
+}
+
+impl Favorites {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns false if the station was already a favorite; its snapshot is
+    /// replaced either way. Stations without a uuid are rejected.
+    pub fn add(&self, station: RadioStation) -> Result<bool, RadioBrowserError> {
+        let Some(uuid) = station.stationuuid.clone() else {
+            return Err(RadioBrowserError::InvalidParameter(format!("station {:?} has no uuid", station.name)));
+        };
+
+        let mut stations = self.stations.write().unwrap();
+        match stations.iter_mut().find(|favorite| favorite.stationuuid.as_ref() == Some(&uuid)) {
+            Some(favorite) => {
+                *favorite = station;
+                Ok(false)
+            }
+            None => {
+                stations.push(station);
+                Ok(true)
+            }
+        }
+    }
+
+    /// Returns whether the station was a favorite.
+    pub fn remove(&self, uuid: impl AsRef<str>) -> bool {
+        let Ok(uuid) = uuid.as_ref().parse::<StationUuid>() else {
+            return false;
+        };
+        let mut stations = self.stations.write().unwrap();
+        let before = stations.len();
+        stations.retain(|favorite| favorite.stationuuid.as_ref() != Some(&uuid));
+        stations.len() != before
+    }
+
+    pub fn contains(&self, uuid: impl AsRef<str>) -> bool {
+        let Ok(uuid) = uuid.as_ref().parse::<StationUuid>() else {
+            return false;
+        };
+        self.stations.read().unwrap().iter().any(|favorite| favorite.stationuuid.as_ref() == Some(&uuid))
+    }
+
+    /// The snapshots, in the order the stations were added.
+    pub fn list(&self) -> Vec<RadioStation> {
+        self.stations.read().unwrap().clone()
+    }
+
+    pub fn len(&self) -> usize {
+        self.stations.read().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Looks all favorites up again with batched uuid requests, bypassing
+    /// the client's cache, and replaces their snapshots with the results.
+    /// Favorites added or removed while the request runs are left alone.
+    pub async fn refresh(&self, client: &RadioBrowserClient) -> Result<FavoritesRefresh, RadioBrowserError> {
+        let uuids: Vec<StationUuid> =
+            self.stations.read().unwrap().iter().filter_map(|favorite| favorite.stationuuid.clone()).collect();
+        if uuids.is_empty() {
+            return Ok(FavoritesRefresh::default());
+        }
+
+        let uuid_strs: Vec<&str> = uuids.iter().map(StationUuid::as_str).collect();
+        let mut current: HashMap<StationUuid, RadioStation> = client
+            .fetch_uuid_batches(&uuid_strs)
+            .await?
+            .into_iter()
+            .filter_map(|station| Some((station.stationuuid.clone()?, station)))
+            .collect();
+
+        let mut refresh = FavoritesRefresh::default();
+        let mut stations = self.stations.write().unwrap();
+        for favorite in stations.iter_mut() {
+            let Some(uuid) = favorite.stationuuid.clone() else {
+                continue;
+            };
+            if !uuids.contains(&uuid) {
+                continue;
+            }
+            match current.remove(&uuid) {
+                Some(station) => {
+                    *favorite = station;
+                    refresh.updated += 1;
+                }
+                None => refresh.missing.push(uuid),
+            }
+        }
+        Ok(refresh)
+    }
+
+    /// Writes the favorites to `path` as JSON. The file is written under a
+    /// temporary name and renamed, so a crash never leaves half a list.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        let path = path.as_ref();
+        let saved = SavedFavorites {
+            version: SAVED_FAVORITES_VERSION,
+            stations: self.list(),
+        };
+        let json = serde_json::to_vec_pretty(&saved)?;
+
+        let tmp = path.with_extension(format!("{}.{:x}.tmp", std::process::id(), rand::random::<u64>()));
+        std::fs::write(&tmp, json)?;
+        if let Err(e) = std::fs::rename(&tmp, path) {
+            let _ = std::fs::remove_file(&tmp);
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Reads favorites written by [`save`](Self::save). A missing file is an
+    /// [`std::io::ErrorKind::NotFound`] error, so a first run can fall back to
+    /// [`Favorites::new`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        let saved: SavedFavorites = serde_json::from_slice(&std::fs::read(path)?)?;
+        if saved.version != SAVED_FAVORITES_VERSION {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("unsupported favorites version {}", saved.version),
+            ));
+        }
+
+        let favorites = Self::new();
+        for station in saved.stations {
+            // Станции без uuid в файл не попадают, разве что его правили руками
+            let _ = favorites.add(station);
+        }
+        Ok(favorites)
+    }
+}
@@ -0,0 +1,231 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use async_trait::async_trait;
+use serde::de::{DeserializeOwned, IgnoredAny};
+use serde::{Deserialize, Serialize};
+
+use crate::{Cache, CacheError, KeyPredicate};
+
+// Время в миллисекундах, чтобы TTL меньше секунды не истекал сразу после записи.
+// Файлы прежнего формата с секундами не читаются и удаляются как испорченные
+#[derive(Serialize, Deserialize)]
+struct FileEntry<V> {
+    key: String,
+    stored_at_ms: u64,
+    #[serde(default)]
+    ttl_ms: Option<u64>,
+    value: V,
+}
+
+/// Keeps cached responses as JSON files in a directory, so results survive
+/// between runs of short-lived programs. Several processes may share the same
+/// directory: files are written to a temporary name and renamed into place.
+/// Corrupt files are treated as misses and removed; other I/O failures are
+/// returned as [`CacheError`].
+pub struct FileCache {
+    dir: PathBuf,
+    max_entries: Option<usize>,
+    ttl: Option<Duration>,
+}
+
+impl FileCache {
+    /// Creates `dir` if it does not exist yet.
+    pub fn new(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+
+        Ok(Self {
+            dir,
+            max_entries: None,
+            ttl: None,
+        })
+    }
+
+    /// When more entries are stored, the oldest files are removed.
+    pub fn with_max_entries(self, max_entries: usize) -> Self {
+        Self {
+            max_entries: Some(max_entries),
+            ..self
+        }
+    }
+
+    /// Entries older than `ttl` are treated as misses and removed when read.
+    pub fn with_ttl(self, ttl: Duration) -> Self {
+        Self { ttl: Some(ttl), ..self }
+    }
+
+    // Сброс не зависит от типа значения, поэтому доступен и без указания Cache<V>
+    pub async fn invalidate(&self, key: &str) -> io::Result<()> {
+        remove(&self.path_for(key)).await
+    }
+
+    pub async fn clear(&self) -> io::Result<()> {
+        for (_, path) in self.entries().await? {
+            remove(&path).await?;
+        }
+        Ok(())
+    }
+
+    /// Removes the entries whose key satisfies `predicate`. Every file is
+    /// read to find its key; unreadable ones are skipped.
+    pub async fn invalidate_matching(&self, predicate: impl Fn(&str) -> bool) -> io::Result<()> {
+        for (_, path) in self.entries().await? {
+            let bytes = match tokio::fs::read(&path).await {
+                Ok(bytes) => bytes,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e),
+            };
+            if let Ok(entry) = serde_json::from_slice::<FileEntry<IgnoredAny>>(&bytes)
+                && predicate(&entry.key)
+            {
+                remove(&path).await?;
+            }
+        }
+        Ok(())
+    }
+
+    fn path_for(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{:016x}.json", fnv1a(key)))
+    }
+
+    fn is_expired<V>(&self, entry: &FileEntry<V>) -> bool {
+        entry
+            .ttl_ms
+            .or(self.ttl.map(as_millis))
+            .is_some_and(|ttl| now_ms().saturating_sub(entry.stored_at_ms) >= ttl)
+    }
+
+    async fn entries(&self) -> io::Result<Vec<(SystemTime, PathBuf)>> {
+        let mut entries = Vec::new();
+        let mut dir = tokio::fs::read_dir(&self.dir).await?;
+
+        while let Some(entry) = dir.next_entry().await? {
+            let path = entry.path();
+            if is_entry_file(&path) {
+                let modified = entry.metadata().await?.modified().unwrap_or(UNIX_EPOCH);
+                entries.push((modified, path));
+            }
+        }
+
+        Ok(entries)
+    }
+
+    async fn enforce_max_entries(&self) -> io::Result<()> {
+        let Some(max_entries) = self.max_entries else {
+            return Ok(());
+        };
+
+        let mut entries = self.entries().await?;
+        if entries.len() > max_entries {
+            entries.sort();
+            for (_, path) in &entries[..entries.len() - max_entries] {
+                remove(path).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn write<V: Serialize>(&self, key: String, value: V, ttl: Option<Duration>) -> io::Result<()> {
+        let path = self.path_for(&key);
+        let entry = FileEntry {
+            key,
+            stored_at_ms: now_ms(),
+            ttl_ms: ttl.map(as_millis),
+            value,
+        };
+        let json = serde_json::to_vec(&entry)?;
+
+        // Соседний процесс никогда не увидит наполовину записанный файл
+        let tmp = path.with_extension(format!("{}.{:x}.tmp", std::process::id(), rand::random::<u64>()));
+        tokio::fs::write(&tmp, json).await?;
+        if let Err(e) = tokio::fs::rename(&tmp, &path).await {
+            let _ = remove(&tmp).await;
+            return Err(e);
+        }
+
+        self.enforce_max_entries().await
+    }
+}
+
+// Один каталог может хранить и станции, и списки: ключи у них не пересекаются
+#[async_trait]
+impl<V> Cache<V> for FileCache
+where
+    V: Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    async fn get(&self, key: &str) -> Result<Option<V>, CacheError> {
+        let path = self.path_for(key);
+        let bytes = match tokio::fs::read(&path).await {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(CacheError::new(e)),
+        };
+
+        let Ok(entry) = serde_json::from_slice::<FileEntry<V>>(&bytes) else {
+            remove(&path).await.map_err(CacheError::new)?;
+            return Ok(None);
+        };
+        // Коллизия хеша: файл принадлежит другому ключу
+        if entry.key != key {
+            return Ok(None);
+        }
+        if self.is_expired(&entry) {
+            remove(&path).await.map_err(CacheError::new)?;
+            return Ok(None);
+        }
+
+        Ok(Some(entry.value))
+    }
+
+    async fn set(&self, key: String, value: V) -> Result<(), CacheError> {
+        self.write(key, value, None).await.map_err(CacheError::new)
+    }
+
+    async fn set_with_ttl(&self, key: String, value: V, ttl: Duration) -> Result<(), CacheError> {
+        self.write(key, value, Some(ttl)).await.map_err(CacheError::new)
+    }
+
+    async fn invalidate(&self, key: &str) -> Result<(), CacheError> {
+        FileCache::invalidate(self, key).await.map_err(CacheError::new)
+    }
+
+    async fn clear(&self) -> Result<(), CacheError> {
+        FileCache::clear(self).await.map_err(CacheError::new)
+    }
+
+    async fn invalidate_matching(&self, predicate: &KeyPredicate) -> Result<(), CacheError> {
+        FileCache::invalidate_matching(self, predicate).await.map_err(CacheError::new)
+    }
+}
+
+// Файл мог уже удалить соседний процесс
+async fn remove(path: &Path) -> io::Result<()> {
+    match tokio::fs::remove_file(path).await {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
+
+// Остальные файлы каталога, в том числе чужие *.json, кеш не трогает
+fn is_entry_file(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "json")
+        && path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .is_some_and(|stem| stem.len() == 16 && stem.bytes().all(|b| b.is_ascii_hexdigit()))
+}
+
+fn as_millis(duration: Duration) -> u64 {
+    duration.as_millis() as u64
+}
+
+fn now_ms() -> u64 {
+    as_millis(SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default())
+}
+
+// Стабильный между версиями Rust хеш, в отличие от DefaultHasher
+fn fnv1a(key: &str) -> u64 {
+    key.bytes().fold(0xcbf29ce484222325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3))
+}
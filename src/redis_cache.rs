@@ -0,0 +1,137 @@
+use std::time::Duration;
+use async_trait::async_trait;
+use redis::AsyncCommands;
+use redis::aio::ConnectionManager;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::{Cache, CacheError, KeyPredicate};
+
+const DEFAULT_NAMESPACE: &str = "radio-browser";
+
+/// Shares cached responses between processes through Redis. Values are
+/// stored as JSON under `<namespace>:<key>`.
+///
+/// Redis errors are returned as [`CacheError`]; the client treats them as
+/// misses, so requests fall through to the network. A value that no longer
+/// deserializes is a miss too.
+pub struct RedisCache {
+    connection: ConnectionManager,
+    namespace: String,
+    ttl: Option<Duration>,
+}
+
+impl RedisCache {
+    /// Connects to the server at `url`, e.g. "redis://127.0.0.1/". The
+    /// connection is re-established automatically after failures.
+    pub async fn connect(url: &str) -> redis::RedisResult<Self> {
+        let client = redis::Client::open(url)?;
+
+        Ok(Self {
+            connection: ConnectionManager::new(client).await?,
+            namespace: DEFAULT_NAMESPACE.to_string(),
+            ttl: None,
+        })
+    }
+
+    /// Prefix for all keys written by this cache. Defaults to "radio-browser".
+    pub fn with_namespace(self, namespace: &str) -> Self {
+        Self {
+            namespace: namespace.to_string(),
+            ..self
+        }
+    }
+
+    /// Sets an expiry on every entry (`SET ... EX`). Without it entries live
+    /// until Redis evicts them.
+    pub fn with_ttl(self, ttl: Duration) -> Self {
+        Self { ttl: Some(ttl), ..self }
+    }
+
+    pub async fn invalidate(&self, key: &str) -> redis::RedisResult<()> {
+        self.connection.clone().del(self.key(key)).await
+    }
+
+    pub async fn clear(&self) -> redis::RedisResult<()> {
+        self.invalidate_matching(|_| true).await
+    }
+
+    /// Removes the entries of this namespace whose key (without the
+    /// namespace prefix) satisfies `predicate`.
+    // Удаляются только ключи своего пространства имён, через SCAN, чтобы не блокировать сервер
+    pub async fn invalidate_matching(&self, predicate: impl Fn(&str) -> bool) -> redis::RedisResult<()> {
+        let mut connection = self.connection.clone();
+        let prefix = format!("{}:", self.namespace);
+        let pattern = format!("{}:*", self.namespace);
+        let mut cursor: u64 = 0;
+
+        loop {
+            let (next, keys): (u64, Vec<String>) = redis::cmd("SCAN")
+                .arg(cursor)
+                .arg("MATCH")
+                .arg(&pattern)
+                .arg("COUNT")
+                .arg(100)
+                .query_async(&mut connection)
+                .await?;
+
+            let keys: Vec<String> =
+                keys.into_iter().filter(|key| key.strip_prefix(&prefix).is_some_and(&predicate)).collect();
+            if !keys.is_empty() {
+                let _: () = connection.del(keys).await?;
+            }
+            if next == 0 {
+                return Ok(());
+            }
+            cursor = next;
+        }
+    }
+
+    fn key(&self, key: &str) -> String {
+        format!("{}:{}", self.namespace, key)
+    }
+
+    async fn store<V: Serialize>(&self, key: String, value: V, ttl: Option<Duration>) -> Result<(), CacheError> {
+        let json = serde_json::to_string(&value).map_err(CacheError::new)?;
+
+        let mut connection = self.connection.clone();
+        let key = self.key(&key);
+        let result: redis::RedisResult<()> = match ttl {
+            // EX 0 Redis отвергает, поэтому не меньше секунды
+            Some(ttl) => connection.set_ex(key, json, ttl.as_secs().max(1) as usize).await,
+            None => connection.set(key, json).await,
+        };
+        result.map_err(CacheError::new)
+    }
+}
+
+#[async_trait]
+impl<V> Cache<V> for RedisCache
+where
+    V: Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    async fn get(&self, key: &str) -> Result<Option<V>, CacheError> {
+        let json: Option<String> = self.connection.clone().get(self.key(key)).await.map_err(CacheError::new)?;
+        Ok(json.and_then(|json| serde_json::from_str(&json).ok()))
+    }
+
+    async fn set(&self, key: String, value: V) -> Result<(), CacheError> {
+        self.store(key, value, self.ttl).await
+    }
+
+    async fn set_with_ttl(&self, key: String, value: V, ttl: Duration) -> Result<(), CacheError> {
+        self.store(key, value, Some(ttl)).await
+    }
+
+    async fn invalidate(&self, key: &str) -> Result<(), CacheError> {
+        RedisCache::invalidate(self, key).await.map_err(CacheError::new)
+    }
+
+    async fn clear(&self) -> Result<(), CacheError> {
+        RedisCache::clear(self).await.map_err(CacheError::new)
+    }
+
+    async fn invalidate_matching(&self, predicate: &KeyPredicate) -> Result<(), CacheError> {
+        RedisCache::invalidate_matching(self, predicate).await.map_err(CacheError::new)
+    }
+}
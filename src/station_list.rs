@@ -0,0 +1,82 @@
+use std::cmp::Reverse;
+
+use crate::RadioStation;
+
+/// Filters and sorts for stations that were already fetched, e.g. after
+/// merging several responses. Each filter returns a new list, so they chain:
+/// `stations.https_only().working_only()`. Sorts work in place, are stable,
+/// and put stations without the value last.
+pub trait StationListExt {
+    /// Stations with this codec, ignoring case ("mp3" matches "MP3").
+    fn filter_codec(&self, codec: &str) -> Vec<RadioStation>;
+
+    /// Stations of at least `bitrate` kbps. Stations without a bitrate are
+    /// dropped.
+    fn filter_min_bitrate(&self, bitrate: u32) -> Vec<RadioStation>;
+
+    /// Stations with this ISO 3166-1 country code, ignoring case.
+    fn filter_country_code(&self, code: &str) -> Vec<RadioStation>;
+
+    /// See [`RadioStation::is_https`].
+    fn https_only(&self) -> Vec<RadioStation>;
+
+    /// Stations whose last check succeeded. Stations that were never checked
+    /// are dropped as well as broken ones.
+    fn working_only(&self) -> Vec<RadioStation>;
+
+    fn sort_by_votes_desc(&mut self);
+
+    fn sort_by_clickcount_desc(&mut self);
+
+    /// Case-insensitive, using Unicode lowercase but no locale rules.
+    fn sort_by_name(&mut self);
+
+    fn sort_by_bitrate_desc(&mut self);
+}
+
+impl StationListExt for [RadioStation] {
+    fn filter_codec(&self, codec: &str) -> Vec<RadioStation> {
+        select(self, |station| station.codec.as_deref().is_some_and(|c| c.trim().eq_ignore_ascii_case(codec.trim())))
+    }
+
+    fn filter_min_bitrate(&self, bitrate: u32) -> Vec<RadioStation> {
+        select(self, |station| station.bitrate.is_some_and(|b| b >= bitrate))
+    }
+
+    fn filter_country_code(&self, code: &str) -> Vec<RadioStation> {
+        select(self, |station| station.countrycode.as_deref().is_some_and(|c| c.eq_ignore_ascii_case(code.trim())))
+    }
+
+    fn https_only(&self) -> Vec<RadioStation> {
+        select(self, RadioStation::is_https)
+    }
+
+    fn working_only(&self) -> Vec<RadioStation> {
+        select(self, |station| station.lastcheckok == Some(true))
+    }
+
+    fn sort_by_votes_desc(&mut self) {
+        self.sort_by_key(|station| descending(station.votes));
+    }
+
+    fn sort_by_clickcount_desc(&mut self) {
+        self.sort_by_key(|station| descending(station.clickcount));
+    }
+
+    fn sort_by_name(&mut self) {
+        self.sort_by_cached_key(|station| station.name.to_lowercase());
+    }
+
+    fn sort_by_bitrate_desc(&mut self) {
+        self.sort_by_key(|station| descending(station.bitrate));
+    }
+}
+
+// (false, ..) < (true, ..): значения идут раньше None
+fn descending<T: Ord>(value: Option<T>) -> (bool, Option<Reverse<T>>) {
+    (value.is_none(), value.map(Reverse))
+}
+
+fn select(stations: &[RadioStation], keep: impl Fn(&RadioStation) -> bool) -> Vec<RadioStation> {
+    stations.iter().filter(|station| keep(station)).cloned().collect()
+}
@@ -0,0 +1,51 @@
+// Без фичи tracing всё здесь сводится к пустым вызовам
+use std::future::Future;
+
+#[cfg(feature = "tracing")]
+const CALL_SPAN: &str = "radio_browser_api";
+
+// Вложенные вызовы (страницы search_all_by_tag, станция по uuid) пишут в span внешнего
+#[cfg(feature = "tracing")]
+pub(crate) async fn call<T, F: Future<Output = T>>(endpoint: &str, call: F) -> T {
+    use tracing::Instrument;
+
+    if tracing::Span::current().metadata().is_some_and(|metadata| metadata.name() == CALL_SPAN) {
+        return call.await;
+    }
+
+    let span = tracing::debug_span!(
+        "radio_browser_api",
+        endpoint,
+        mirror = tracing::field::Empty,
+        cache_hit = tracing::field::Empty,
+        status = tracing::field::Empty,
+        elapsed_ms = tracing::field::Empty,
+        count = tracing::field::Empty,
+    );
+    let started = crate::rt::Instant::now();
+    let result = call.instrument(span.clone()).await;
+    span.record("elapsed_ms", started.elapsed().as_millis() as u64);
+    result
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) async fn call<T, F: Future<Output = T>>(_endpoint: &str, call: F) -> T {
+    call.await
+}
+
+#[cfg(feature = "tracing")]
+pub(crate) fn record(field: &str, value: impl tracing::Value) {
+    tracing::Span::current().record(field, value);
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn record<V>(_field: &str, _value: V) {}
+
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "tracing")]
+        tracing::debug!($($arg)*);
+    };
+}
+
+pub(crate) use debug;